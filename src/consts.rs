@@ -0,0 +1,36 @@
+//! Fixed key / IV / message material shared by the witness generators.
+//!
+//! These are deterministic test vectors, not secrets: every mode in
+//! `witness::aes_witnesses` pins its inputs so the generated JSON witness
+//! can be diffed against known-good circom outputs.
+
+/// ASCII key used to exercise the TLS1.3-flavoured demo path.
+pub(crate) const KEY_ASCII: &str = "AAAAAAAAAAAAAAAA";
+/// ASCII IV used to exercise the TLS1.3-flavoured demo path.
+pub(crate) const IV_ASCII: &str = "AAAAAAAAAAAA";
+/// Plaintext encrypted by the TLS1.3-flavoured demo path.
+pub(crate) const MESSAGE: &str = "Hello, world! Pluto AES circuits.";
+
+/// Raw 128-bit key, shared by every 128-bit mode (vanilla, CTR-128,
+/// GCM-128 and GCM-SIV-128).
+pub(crate) const KEY_BYTES_156: [u8; 16] = *b"0123456789abcdef";
+/// Raw 256-bit key.
+pub(crate) const KEY_BYTES_256: [u8; 32] = *b"0123456789abcdef0123456789abcdef";
+
+/// Full 16-byte IV (nonce || counter) used to seed AES-128-CTR.
+pub(crate) const IV_BYTES: [u8; 16] = *b"1234567890123456";
+/// Full 16-byte IV (nonce || counter) used to seed AES-256-CTR.
+pub(crate) const IV_BYTES_256: [u8; 16] = *b"1234567890123456";
+/// 12-byte GCM nonce paired with [`KEY_BYTES_156`], for both GCM-128 and
+/// GCM-SIV-128.
+pub(crate) const IV_BYTES_SHORT: [u8; 12] = *b"123456789012";
+/// 12-byte GCM nonce paired with [`KEY_BYTES_256`].
+pub(crate) const IV_BYTES_SHORT_256: [u8; 12] = *b"123456789012";
+
+/// Single AES block of plaintext, used by the vanilla/CTR-128/GCM(-SIV)-128 paths.
+pub(crate) const MESSAGE_BYTES: [u8; 16] = *b"abcdefghijklmnop";
+/// Two all-zero blocks of plaintext, used by the 256-bit GCM(-SIV) paths.
+pub(crate) const ZERO_MESSAGE_BYTES_256: [u8; 32] = [0u8; 32];
+
+/// AAD shared by every GCM(-SIV) fixture, 128- and 256-bit alike.
+pub(crate) const SIV_AAD: [u8; 5] = *b"aadaa";
@@ -57,8 +57,17 @@ mod tests {
     // Test the AES-GCM-SIV circuit (from electron labs)
     #[tokio::test]
     async fn test_aes_gcm_siv() {
+        use crate::consts::{IV_BYTES_SHORT_256, KEY_BYTES_256, SIV_AAD, ZERO_MESSAGE_BYTES_256};
+
         // generate witness
-        let mut witness = witness::aes_witnesses(witness::CipherMode::GcmSiv).unwrap();
+        let mut witness = witness::aead_witness(
+            "gcm_siv",
+            &KEY_BYTES_256,
+            &IV_BYTES_SHORT_256,
+            &SIV_AAD,
+            &ZERO_MESSAGE_BYTES_256,
+        )
+        .unwrap();
 
         // log one of them
         println!(
@@ -127,4 +136,141 @@ mod tests {
         println!("msg={}", hex::encode(message));
         println!("ct={}", hex::encode(ct));
     }
+
+    // J0 derivation: a 96-bit nonce takes the fast path (`nonce || 0^31 ||
+    // 1`, no padding), and a non-96-bit nonce takes the GHASH path without
+    // panicking, reporting how many zero bytes padded it to a block.
+    #[test]
+    fn test_j0_non_96_bit_nonce() {
+        use crate::consts::IV_BYTES_SHORT;
+
+        let h = Block::default();
+
+        let (j0_fast, pad_fast) = utils::compute_j0(&h, &IV_BYTES_SHORT);
+        assert_eq!(pad_fast, 0);
+        assert_eq!(&j0_fast[..12], &IV_BYTES_SHORT);
+        assert_eq!(j0_fast[15], 1);
+
+        let short_nonce = [0x42u8; 8];
+        let (_j0_ghash, pad_ghash) = utils::compute_j0(&h, &short_nonce);
+        assert_eq!(pad_ghash, 8); // padded up to the 16-byte block
+    }
+
+    // RFC 8452 Appendix A POLYVAL known-answer test.
+    #[test]
+    fn test_polyval_kat() {
+        let h: Block =
+            GenericArray::clone_from_slice(&hex::decode("25629347589242761d31f826ba4b757b").unwrap());
+        let x1: Block =
+            GenericArray::clone_from_slice(&hex::decode("4f4f95668c83dfb6401762bb2d01a262").unwrap());
+        let x2: Block =
+            GenericArray::clone_from_slice(&hex::decode("d1a24ddd2721d006bbe45f20d3c9f362").unwrap());
+
+        let s = utils::polyval(&h, &[x1, x2]);
+        assert_eq!(hex::encode(s), "f7a3b47b846119fae5b7866cf5e5b77e");
+    }
+
+    // 27 bytes of plaintext (one full block plus 11 bytes): exercises the
+    // truncated final keystream block.
+    #[test]
+    fn test_gcm128_partial_final_block() {
+        use crate::consts::{IV_BYTES_SHORT, KEY_BYTES_156, SIV_AAD};
+
+        let pt = [0xabu8; 27];
+        let witness = witness::aead_witness("gcm128", &KEY_BYTES_156, &IV_BYTES_SHORT, &SIV_AAD, &pt).unwrap();
+
+        assert_eq!(witness.ct.len(), pt.len());
+        assert_eq!(witness.block_count, Some(2));
+        assert_eq!(witness.last_block_len, Some(11));
+    }
+
+    // A forged tag must fail the constant-time equality gate closed.
+    #[test]
+    fn test_gcm128_decrypt_rejects_forged_tag() {
+        use crate::consts::{IV_BYTES_SHORT, KEY_BYTES_156, MESSAGE_BYTES, SIV_AAD};
+
+        let witness =
+            witness::aead_witness("gcm128_decrypt", &KEY_BYTES_156, &IV_BYTES_SHORT, &SIV_AAD, &MESSAGE_BYTES)
+                .unwrap();
+        assert_eq!(witness.authentic, Some(true));
+
+        let forged = witness::aead_witness(
+            "gcm128_decrypt_forged",
+            &KEY_BYTES_156,
+            &IV_BYTES_SHORT,
+            &SIV_AAD,
+            &MESSAGE_BYTES,
+        )
+        .unwrap();
+        assert_eq!(forged.authentic, Some(false));
+    }
+
+    // The registry-based dispatch rejects an unknown mode name instead of
+    // panicking.
+    #[test]
+    fn test_aead_witness_unknown_mode() {
+        assert!(witness::aead_witness("not_a_real_mode", &[], &[], &[], &[]).is_err());
+    }
+
+    // The GHASH-derived-J0 path must be reachable through the public
+    // `aead_witness` entrypoint, not just `utils::compute_j0` directly.
+    #[test]
+    fn test_gcm128_non_96_bit_nonce_through_aead_witness() {
+        use crate::consts::{KEY_BYTES_156, MESSAGE_BYTES, SIV_AAD};
+
+        // McGrew Test Case 5: an 8-byte IV, which forces the GHASH path.
+        let nonce = [0xcau8, 0xfe, 0xba, 0xbe, 0xfa, 0xce, 0xdb, 0xad];
+        let witness =
+            witness::aead_witness("gcm128", &KEY_BYTES_156, &nonce, &SIV_AAD, &MESSAGE_BYTES).unwrap();
+
+        assert_eq!(witness.nonce_pad_len, Some(8));
+        assert_eq!(witness.iv, nonce);
+    }
+
+    // AES-128-GCM-SIV: same construction as the 256-bit variant, derived
+    // from a 16-byte key-generating key.
+    #[test]
+    fn test_gcm_siv_128() {
+        use crate::consts::{IV_BYTES_SHORT, KEY_BYTES_156, MESSAGE_BYTES, SIV_AAD};
+
+        let witness =
+            witness::aead_witness("gcm_siv_128", &KEY_BYTES_156, &IV_BYTES_SHORT, &SIV_AAD, &MESSAGE_BYTES)
+                .unwrap();
+
+        assert_eq!(witness.mac_key.as_ref().map(Vec::len), Some(16));
+        assert_eq!(witness.enc_key.as_ref().map(Vec::len), Some(16));
+        assert_eq!(witness.ct.len(), MESSAGE_BYTES.len() + 16); // ciphertext || tag
+
+        // The recomputed tag must agree with the tag the ciphertext
+        // itself carries, or the witness is internally inconsistent.
+        let (_, ct_tag) = witness.ct.split_at(witness.ct.len() - 16);
+        assert_eq!(witness.tag.as_deref(), Some(ct_tag));
+    }
+
+    // A genuine AES-256-GCM-SIV decryption must be reported authentic,
+    // and a forged tag must fail the constant-time equality gate closed.
+    #[test]
+    fn test_gcm_siv_decrypt_rejects_forged_tag() {
+        use crate::consts::{IV_BYTES_SHORT_256, KEY_BYTES_256, SIV_AAD, ZERO_MESSAGE_BYTES_256};
+
+        let witness = witness::aead_witness(
+            "gcm_siv_decrypt",
+            &KEY_BYTES_256,
+            &IV_BYTES_SHORT_256,
+            &SIV_AAD,
+            &ZERO_MESSAGE_BYTES_256,
+        )
+        .unwrap();
+        assert_eq!(witness.authentic, Some(true));
+
+        let forged = witness::aead_witness(
+            "gcm_siv_decrypt_forged",
+            &KEY_BYTES_256,
+            &IV_BYTES_SHORT_256,
+            &SIV_AAD,
+            &ZERO_MESSAGE_BYTES_256,
+        )
+        .unwrap();
+        assert_eq!(forged.authentic, Some(false));
+    }
 }
@@ -0,0 +1,185 @@
+//! Stream-cipher and GHASH helpers shared by the witness generators.
+
+use aes::cipher::StreamCipherCore;
+
+use crate::{witness::Witness, Block, AAD};
+
+/// XOR `buf` in place with the keystream produced by `cipher`, one AES
+/// block at a time. The final chunk may be shorter than a full block, in
+/// which case only that many keystream bytes are applied.
+pub(crate) fn apply_keystream<C: StreamCipherCore<BlockSize = cipher::consts::U16>>(
+    mut cipher: C,
+    buf: &mut [u8],
+) {
+    for chunk in buf.chunks_mut(16) {
+        let mut keystream = Block::default();
+        cipher.write_keystream_block(&mut keystream);
+        for (b, k) in chunk.iter_mut().zip(keystream.iter()) {
+            *b ^= k;
+        }
+    }
+}
+
+/// TLS1.3 nonce construction: XOR the 64-bit big-endian sequence number
+/// into the low 8 bytes of the fixed per-connection IV.
+pub(crate) fn make_nonce(fixed_iv: [u8; 12], seq: u64) -> [u8; 12] {
+    let mut nonce = fixed_iv;
+    for (n, s) in nonce[4..].iter_mut().zip(seq.to_be_bytes().iter()) {
+        *n ^= s;
+    }
+    nonce
+}
+
+/// TLS1.3 additional data: record type `0x17` (application data), protocol
+/// version `0x0303`, and the 16-bit big-endian record length.
+pub(crate) fn make_tls13_aad(total_len: usize) -> AAD {
+    let len = (total_len as u16).to_be_bytes();
+    [0x17, 0x03, 0x03, len[0], len[1]]
+}
+
+/// Write `witness` out as the JSON fixture consumed by the circom tests,
+/// named after the cipher mode that produced it.
+pub(crate) fn make_json_witness(
+    witness: &Witness,
+    mode: crate::witness::CipherMode,
+) -> std::io::Result<()> {
+    let path = format!("./witnesses/{}.json", mode.name());
+    let json = serde_json::to_string_pretty(witness)?;
+    std::fs::write(path, json)
+}
+
+/// Split `data` into 16-byte GHASH/POLYVAL input blocks, zero-padding the
+/// final block if `data.len()` isn't a multiple of 16. Empty input yields
+/// no blocks, matching the convention of skipping empty AAD/CT.
+pub(crate) fn pad_to_blocks(data: &[u8]) -> Vec<Block> {
+    data.chunks(16)
+        .map(|chunk| {
+            let mut block = Block::default();
+            block[..chunk.len()].copy_from_slice(chunk);
+            block
+        })
+        .collect()
+}
+
+/// Multiply two GF(2^128) elements under the bit-reflected reduction
+/// polynomial used by GHASH (NIST SP 800-38D, section 6.3).
+fn gf128_mul_ghash(x: &Block, y: &Block) -> Block {
+    let mut z = Block::default();
+    let mut v = *y;
+
+    for byte in x.iter() {
+        for bit in (0..8).rev() {
+            if (byte >> bit) & 1 == 1 {
+                for (zb, vb) in z.iter_mut().zip(v.iter()) {
+                    *zb ^= vb;
+                }
+            }
+            let lsb = v[15] & 1;
+            for i in (1..16).rev() {
+                v[i] = (v[i] >> 1) | (v[i - 1] << 7);
+            }
+            v[0] >>= 1;
+            if lsb == 1 {
+                v[0] ^= 0xe1;
+            }
+        }
+    }
+    z
+}
+
+/// Run the GHASH recurrence `X_i = (X_{i-1} XOR block_i) * H` over
+/// `blocks`, returning every intermediate `X_i` (the last entry is the
+/// GHASH output, `S`).
+pub(crate) fn ghash_chain(h: &Block, blocks: &[Block]) -> Vec<Block> {
+    let mut x = Block::default();
+    let mut out = Vec::with_capacity(blocks.len());
+    for block in blocks {
+        for (xb, bb) in x.iter_mut().zip(block.iter()) {
+            *xb ^= bb;
+        }
+        x = gf128_mul_ghash(h, &x);
+        out.push(x);
+    }
+    out
+}
+
+/// Multiply `x` by the field element `x^1` under GHASH's bit-reflected
+/// convention (one step of [`gf128_mul_ghash`]'s inner shift-and-reduce,
+/// standalone).
+fn mulx_ghash(x: &Block) -> Block {
+    let mut v = *x;
+    let lsb = v[15] & 1;
+    for i in (1..16).rev() {
+        v[i] = (v[i] >> 1) | (v[i - 1] << 7);
+    }
+    v[0] >>= 1;
+    if lsb == 1 {
+        v[0] ^= 0xe1;
+    }
+    v
+}
+
+/// Reverse the byte (not bit) order of a block.
+fn byte_reverse(x: &Block) -> Block {
+    let mut out = Block::default();
+    for (o, b) in out.iter_mut().zip(x.iter().rev()) {
+        *o = *b;
+    }
+    out
+}
+
+/// Multiply two GF(2^128) elements under POLYVAL's native little-endian
+/// convention (RFC 8452 section 3). POLYVAL and GHASH use the same
+/// reduction polynomial up to a change of bit convention, so this is
+/// computed by byte-reversing into GHASH's bit-reflected convention,
+/// running GHASH's multiply, and byte-reversing back (RFC 8452 Appendix
+/// A): `POLYVAL(H, X) = ByteReverse(GHASH(mulX_GHASH(ByteReverse(H)),
+/// ByteReverse(X)))`. `mulX_GHASH` — an extra multiply-by-`x` in GHASH's
+/// convention — is the correction this reuse needs; omitting it silently
+/// produces the wrong product.
+fn polyval_mul(x: &Block, y: &Block) -> Block {
+    let h = mulx_ghash(&byte_reverse(x));
+    let v = byte_reverse(y);
+    byte_reverse(&gf128_mul_ghash(&h, &v))
+}
+
+/// Run the POLYVAL recurrence `S_i = (S_{i-1} XOR block_i) * H` over
+/// `blocks` (RFC 8452 section 3), returning the final accumulator `S`.
+pub(crate) fn polyval(h: &Block, blocks: &[Block]) -> Block {
+    let mut s = Block::default();
+    for block in blocks {
+        for (sb, bb) in s.iter_mut().zip(block.iter()) {
+            *sb ^= bb;
+        }
+        s = polyval_mul(h, &s);
+    }
+    s
+}
+
+/// Compute the initial counter block `J0` for a GCM nonce of any length.
+///
+/// For the common 96-bit nonce this is just `nonce || 0^31 || 1`. For any
+/// other length it's `GHASH_H(nonce padded to a block multiple || 0^64 ||
+/// len(nonce)_64)`, per NIST SP 800-38D section 7.1.
+///
+/// Returns `(J0, nonce_pad_len)`, where `nonce_pad_len` is the number of
+/// zero bytes used to pad the nonce up to a block multiple (`0` on the
+/// 96-bit fast path, since no GHASH padding is involved there).
+pub(crate) fn compute_j0(h: &Block, nonce: &[u8]) -> (Block, usize) {
+    if nonce.len() == 12 {
+        let mut j0 = Block::default();
+        j0[..12].copy_from_slice(nonce);
+        j0[15] = 1;
+        return (j0, 0);
+    }
+
+    let mut blocks = pad_to_blocks(nonce);
+    let nonce_pad_len = blocks.len() * 16 - nonce.len();
+
+    let mut len_block = Block::default();
+    len_block[8..].copy_from_slice(&((nonce.len() as u64) * 8).to_be_bytes());
+    blocks.push(len_block);
+
+    let chain = ghash_chain(h, &blocks);
+    (*chain.last().expect("length block is always present"), nonce_pad_len)
+}
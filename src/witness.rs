@@ -8,10 +8,19 @@
 //! - AES is processed in 16 byte chunks. The chunks are then appended together.
 //! - AES-CTR is a subset of GCM with some adjustments to IV prep (16 bytes)
 //! - AES-GCM can be decrypted by AES-CTR, by skipping the auth tag and setting up the IV correctly.
+//!
+//! The real AEAD constructions (GCM-128, GCM-SIV-256 and GCM-SIV-128, plus
+//! decrypt-and-verify counterparts of the first two) are implemented against the
+//! [`AeadWitness`] trait below and selected by name through [`registry`] /
+//! [`aead_witness`], rather than the `CipherMode` match further down, which
+//! is reserved for the simpler non-AEAD demo paths (vanilla block, raw CTR,
+//! the off-the-shelf `aes_gcm` crate).
+
+use std::collections::HashMap;
 
 use aes::{
     cipher::{BlockEncrypt, InnerIvInit, KeyInit, KeyIvInit, StreamCipher, StreamCipherCore},
-    Aes128,
+    Aes128, Aes256,
 };
 use aes_gcm::{
     aead::{generic_array::GenericArray, Aead, NewAead, Payload},
@@ -19,10 +28,14 @@ use aes_gcm::{
 };
 use anyhow::Result;
 use serde::Serialize;
+use subtle::ConstantTimeEq;
 
 use crate::{
     consts::*,
-    utils::{apply_keystream, make_nonce, make_tls13_aad},
+    utils::{
+        apply_keystream, compute_j0, ghash_chain, make_nonce, make_tls13_aad, pad_to_blocks,
+        polyval,
+    },
     Aes128Ctr32BE, Aes256Ctr32BE, Block, Ctr32BE,
 };
 
@@ -33,6 +46,48 @@ pub struct Witness {
     pub iv:  Vec<u8>,
     pub ct:  Vec<u8>,
     pub pt:  Vec<u8>,
+    /// GHASH hash subkey `H = AES_K(0^128)`. Only set for GCM witnesses.
+    pub h:   Option<Vec<u8>>,
+    /// Initial counter block `J0`. Only set for GCM witnesses.
+    pub j0:  Option<Vec<u8>>,
+    /// Per-block GHASH accumulator `X_i`, in order, with the final entry
+    /// covering the `len(AAD) || len(CT)` block. Only set for GCM
+    /// witnesses.
+    pub ghash_blocks: Option<Vec<Vec<u8>>>,
+    /// Authentication tag: `GHASH XOR AES_K(J0)` for GCM witnesses, or
+    /// `AES_K(POLYVAL accumulator XOR nonce)` for GCM-SIV witnesses. Set
+    /// for both.
+    pub tag: Option<Vec<u8>>,
+    /// Zero bytes used to pad the nonce to a GHASH block multiple when
+    /// deriving `J0` for a non-96-bit nonce (`0` on the 96-bit fast path).
+    /// Only set for GCM witnesses.
+    pub nonce_pad_len: Option<usize>,
+    /// Number of 16-byte keystream blocks applied to the plaintext,
+    /// including a truncated final block. Only set for GCM witnesses.
+    pub block_count: Option<usize>,
+    /// Valid byte length of the last keystream block (`16` unless the
+    /// plaintext length isn't a multiple of 16, and `0` for an empty
+    /// plaintext). Only set for GCM witnesses.
+    pub last_block_len: Option<usize>,
+    /// RFC 8452 per-nonce message-authentication key. Only set for
+    /// GCM-SIV witnesses.
+    pub mac_key: Option<Vec<u8>>,
+    /// RFC 8452 per-nonce message-encryption key. Only set for GCM-SIV
+    /// witnesses.
+    pub enc_key: Option<Vec<u8>>,
+    /// POLYVAL accumulator over AAD, plaintext and the bit-length block.
+    /// Only set for GCM-SIV witnesses.
+    pub polyval: Option<Vec<u8>>,
+    /// Initial CTR32-LE counter block, seeded from the tag. Only set for
+    /// GCM-SIV witnesses.
+    pub ctr_seed: Option<Vec<u8>>,
+    /// Tag carried alongside the ciphertext being verified (as opposed to
+    /// `tag`, the independently-recomputed expected value). Only set for
+    /// decrypt-and-verify witnesses.
+    pub received_tag: Option<Vec<u8>>,
+    /// Whether `tag` and `received_tag` matched under a constant-time
+    /// comparison. Only set for decrypt-and-verify witnesses.
+    pub authentic: Option<bool>,
 }
 
 #[derive(Debug, Serialize)]
@@ -45,21 +100,628 @@ pub struct AesGcmSivInputs {
 
 impl Witness {
     pub fn new(key: &[u8], iv: &[u8], ct: &[u8], pt: &[u8]) -> Self {
-        Self { key: key.to_vec(), iv: iv.to_vec(), ct: ct.to_vec(), pt: pt.to_vec() }
+        Self {
+            key: key.to_vec(),
+            iv: iv.to_vec(),
+            ct: ct.to_vec(),
+            pt: pt.to_vec(),
+            h: None,
+            j0: None,
+            ghash_blocks: None,
+            tag: None,
+            nonce_pad_len: None,
+            block_count: None,
+            last_block_len: None,
+            mac_key: None,
+            enc_key: None,
+            polyval: None,
+            ctr_seed: None,
+            received_tag: None,
+            authentic: None,
+        }
+    }
+
+    /// Build a witness for a GCM-style mode, additionally recording the
+    /// GHASH intermediates a circuit needs in order to verify the tag.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_gcm(
+        key: &[u8],
+        iv: &[u8],
+        ct: &[u8],
+        pt: &[u8],
+        h: &Block,
+        j0: &Block,
+        ghash_blocks: &[Block],
+        tag: &Block,
+        nonce_pad_len: usize,
+    ) -> Self {
+        let block_count = pt.len().div_ceil(16);
+        let last_block_len = match pt.len() % 16 {
+            0 if pt.is_empty() => 0,
+            0 => 16,
+            rem => rem,
+        };
+        Self {
+            key: key.to_vec(),
+            iv: iv.to_vec(),
+            ct: ct.to_vec(),
+            pt: pt.to_vec(),
+            h: Some(h.to_vec()),
+            j0: Some(j0.to_vec()),
+            ghash_blocks: Some(ghash_blocks.iter().map(|b| b.to_vec()).collect()),
+            tag: Some(tag.to_vec()),
+            nonce_pad_len: Some(nonce_pad_len),
+            block_count: Some(block_count),
+            last_block_len: Some(last_block_len),
+            mac_key: None,
+            enc_key: None,
+            polyval: None,
+            ctr_seed: None,
+            received_tag: None,
+            authentic: None,
+        }
+    }
+
+    /// Build a witness for an AES-GCM-SIV mode, recording the RFC 8452
+    /// per-nonce key derivation and POLYVAL intermediates the
+    /// `gcm_siv_dec_2_keys` circuit needs.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_gcm_siv(
+        key: &[u8],
+        iv: &[u8],
+        ct: &[u8],
+        pt: &[u8],
+        mac_key: &[u8],
+        enc_key: &[u8],
+        polyval: &Block,
+        tag: &Block,
+        ctr_seed: &Block,
+    ) -> Self {
+        Self {
+            key: key.to_vec(),
+            iv: iv.to_vec(),
+            ct: ct.to_vec(),
+            pt: pt.to_vec(),
+            h: None,
+            j0: None,
+            ghash_blocks: None,
+            tag: Some(tag.to_vec()),
+            nonce_pad_len: None,
+            block_count: None,
+            last_block_len: None,
+            mac_key: Some(mac_key.to_vec()),
+            enc_key: Some(enc_key.to_vec()),
+            polyval: Some(polyval.to_vec()),
+            ctr_seed: Some(ctr_seed.to_vec()),
+            received_tag: None,
+            authentic: None,
+        }
+    }
+
+    /// Build a witness for the AES-GCM-SIV decrypt-and-verify mode: like
+    /// `new_gcm_siv`, but additionally records the `received_tag` that
+    /// travelled with the ciphertext and whether it matched the
+    /// independently-recomputed `tag`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_gcm_siv_decrypt(
+        key: &[u8],
+        iv: &[u8],
+        ct: &[u8],
+        pt: &[u8],
+        mac_key: &[u8],
+        enc_key: &[u8],
+        polyval: &Block,
+        ctr_seed: &Block,
+        expected_tag: &Block,
+        received_tag: &Block,
+        authentic: bool,
+    ) -> Self {
+        Self {
+            key: key.to_vec(),
+            iv: iv.to_vec(),
+            ct: ct.to_vec(),
+            pt: pt.to_vec(),
+            h: None,
+            j0: None,
+            ghash_blocks: None,
+            tag: Some(expected_tag.to_vec()),
+            nonce_pad_len: None,
+            block_count: None,
+            last_block_len: None,
+            mac_key: Some(mac_key.to_vec()),
+            enc_key: Some(enc_key.to_vec()),
+            polyval: Some(polyval.to_vec()),
+            ctr_seed: Some(ctr_seed.to_vec()),
+            received_tag: Some(received_tag.to_vec()),
+            authentic: Some(authentic),
+        }
+    }
+
+    /// Build a witness for a decrypt-and-verify mode: records the
+    /// independently-recomputed `tag` alongside the `received_tag` that
+    /// travelled with the ciphertext, and whether they matched.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_gcm_decrypt(
+        key: &[u8],
+        iv: &[u8],
+        ct: &[u8],
+        pt: &[u8],
+        h: &Block,
+        j0: &Block,
+        ghash_blocks: &[Block],
+        expected_tag: &Block,
+        received_tag: &Block,
+        authentic: bool,
+    ) -> Self {
+        Self {
+            key: key.to_vec(),
+            iv: iv.to_vec(),
+            ct: ct.to_vec(),
+            pt: pt.to_vec(),
+            h: Some(h.to_vec()),
+            j0: Some(j0.to_vec()),
+            ghash_blocks: Some(ghash_blocks.iter().map(|b| b.to_vec()).collect()),
+            tag: Some(expected_tag.to_vec()),
+            nonce_pad_len: None,
+            block_count: None,
+            last_block_len: None,
+            mac_key: None,
+            enc_key: None,
+            polyval: None,
+            ctr_seed: None,
+            received_tag: Some(received_tag.to_vec()),
+            authentic: Some(authentic),
+        }
     }
 }
 
-/// AES cipher modes.
+/// A single AEAD construction's witness generator: given a key, nonce, AAD
+/// and plaintext, produce the `Witness` a matching circuit needs (its own
+/// keystream, tag derivation and `Witness` shape). Implementors are
+/// zero-sized marker types registered by name in [`registry`], so adding a
+/// construction (a new nonce length, key size, or decrypt path) means
+/// writing one new impl rather than another arm of a central match.
+pub(crate) trait AeadWitness {
+    /// Authentication tag length in bytes.
+    const TAG_SIZE: usize;
+    /// Nonce representation this construction expects.
+    type Nonce: for<'a> TryFrom<&'a [u8]>;
+
+    fn gen(key: &[u8], nonce: Self::Nonce, aad: &[u8], pt: &[u8]) -> Result<Witness>;
+}
+
+/// AES-128-GCM, with the full GHASH tag derivation so a circuit can verify
+/// the tag and not just the ciphertext.
+pub(crate) struct Gcm128;
+/// AES-128-GCM decrypt-and-verify: recomputes the tag and checks it in
+/// constant time against the one carried with the ciphertext.
+pub(crate) struct Gcm128Decrypt;
+/// Same as [`Gcm128Decrypt`], but deliberately corrupts the carried tag so
+/// the witness demonstrates a rejected forgery.
+pub(crate) struct Gcm128DecryptForged;
+
+/// Seal `pt` under AES-128-GCM, returning every intermediate
+/// (`ciphertext, H, J0, GHASH chain, tag, nonce_pad_len`) a `Gcm128`
+/// witness or its decrypt-and-verify counterparts need.
+#[allow(clippy::type_complexity)]
+fn gcm128_seal(
+    key: &[u8],
+    nonce: &[u8],
+    aad: &[u8],
+    pt: &[u8],
+) -> (Vec<u8>, Block, Block, Vec<Block>, Block, usize) {
+    let inner = Aes128::new(GenericArray::from_slice(key));
+
+    // H = AES_K(0^128)
+    let mut h = Block::default();
+    inner.encrypt_block(&mut h);
+
+    // J0: fast path for a 96-bit nonce, or GHASH-derived otherwise (see
+    // `utils::compute_j0` for non-96-bit nonces, e.g. some TLS/SSH
+    // implementations).
+    let (j0, nonce_pad_len) = compute_j0(&h, nonce);
+
+    // CTR keystream starting at J0 encrypts the tag mask, then the
+    // plaintext starting at J0 + 1.
+    let mut ctr = Ctr32BE::inner_iv_init(&inner, &j0);
+    let mut tag_mask = Block::default();
+    ctr.write_keystream_block(&mut tag_mask);
+
+    let mut ciphertext = pt.to_vec();
+    apply_keystream(ctr, &mut ciphertext);
+
+    // GHASH over the AAD blocks, then the ciphertext blocks, then the
+    // `len(AAD)_64 || len(CT)_64` length block (both in bits).
+    let mut blocks = pad_to_blocks(aad);
+    blocks.extend(pad_to_blocks(&ciphertext));
+    let mut len_block = Block::default();
+    len_block[..8].copy_from_slice(&((aad.len() as u64) * 8).to_be_bytes());
+    len_block[8..].copy_from_slice(&((ciphertext.len() as u64) * 8).to_be_bytes());
+    blocks.push(len_block);
+
+    let ghash_blocks = ghash_chain(&h, &blocks);
+    let s = *ghash_blocks.last().expect("length block is always present");
+
+    // tag = GHASH XOR AES_K(J0)
+    let mut tag = s;
+    for (t, m) in tag.iter_mut().zip(tag_mask.iter()) {
+        *t ^= m;
+    }
+
+    (ciphertext, h, j0, ghash_blocks, tag, nonce_pad_len)
+}
+
+/// Shared decrypt-and-verify logic for AES-128-GCM: re-seal `pt` to obtain
+/// a ciphertext and genuine tag, optionally corrupt the tag, recover the
+/// plaintext via CTR and check the tag in constant time.
+fn gcm128_decrypt(key: &[u8], nonce: &[u8], aad: &[u8], pt: &[u8], forge: bool) -> Result<Witness> {
+    let (ciphertext, h, j0, ghash_blocks, expected_tag, _nonce_pad_len) =
+        gcm128_seal(key, nonce, aad, pt);
+
+    // The "received" tag is the genuine one, unless this fixture is
+    // deliberately corrupting it.
+    let mut received_tag = expected_tag;
+    if forge {
+        received_tag[0] ^= 0x01;
+    }
+    let authentic: bool = expected_tag.ct_eq(&received_tag).into();
+
+    let inner = Aes128::new(GenericArray::from_slice(key));
+    let mut ctr = Ctr32BE::inner_iv_init(&inner, &j0);
+    let mut tag_mask = Block::default();
+    ctr.write_keystream_block(&mut tag_mask);
+    let mut plaintext = ciphertext.clone();
+    apply_keystream(ctr, &mut plaintext);
+
+    Ok(Witness::new_gcm_decrypt(
+        key,
+        nonce,
+        &ciphertext,
+        &plaintext,
+        &h,
+        &j0,
+        &ghash_blocks,
+        &expected_tag,
+        &received_tag,
+        authentic,
+    ))
+}
+
+// `Gcm128`/`Gcm128Decrypt`/`Gcm128DecryptForged` accept a nonce of any
+// length (`compute_j0` takes the 96-bit fast path or the GHASH-derived
+// one as appropriate), so their `Nonce` is `Vec<u8>` rather than a fixed
+// `[u8; 12]` — the latter would make the GHASH-derived-J0 path
+// unreachable from `aead_witness`.
+impl AeadWitness for Gcm128 {
+    const TAG_SIZE: usize = 16;
+    type Nonce = Vec<u8>;
+
+    fn gen(key: &[u8], nonce: Self::Nonce, aad: &[u8], pt: &[u8]) -> Result<Witness> {
+        let (ciphertext, h, j0, ghash_blocks, tag, nonce_pad_len) = gcm128_seal(key, &nonce, aad, pt);
+        Ok(Witness::new_gcm(key, &nonce, &ciphertext, pt, &h, &j0, &ghash_blocks, &tag, nonce_pad_len))
+    }
+}
+
+impl AeadWitness for Gcm128Decrypt {
+    const TAG_SIZE: usize = 16;
+    type Nonce = Vec<u8>;
+
+    fn gen(key: &[u8], nonce: Self::Nonce, aad: &[u8], pt: &[u8]) -> Result<Witness> {
+        gcm128_decrypt(key, &nonce, aad, pt, false)
+    }
+}
+
+impl AeadWitness for Gcm128DecryptForged {
+    const TAG_SIZE: usize = 16;
+    type Nonce = Vec<u8>;
+
+    fn gen(key: &[u8], nonce: Self::Nonce, aad: &[u8], pt: &[u8]) -> Result<Witness> {
+        gcm128_decrypt(key, &nonce, aad, pt, true)
+    }
+}
+
+/// AES-256-GCM-SIV (RFC 8452), recording the per-nonce key derivation and
+/// POLYVAL intermediates the `gcm_siv_dec_2_keys` circuit needs.
+pub(crate) struct GcmSiv256;
+/// AES-256-GCM-SIV decrypt-and-verify: uses the received tag to seed
+/// CTR32-LE, recovers the plaintext, then re-derives the expected tag.
+pub(crate) struct GcmSiv256Decrypt;
+/// Same as [`GcmSiv256Decrypt`], but deliberately corrupts the carried
+/// tag so the witness demonstrates a rejected forgery.
+pub(crate) struct GcmSiv256DecryptForged;
+
+/// One `AES_K(i_le32 || N)[:8]` counter-block half used by the RFC 8452
+/// per-nonce key derivation, for any block cipher `C`.
+fn gcm_siv_derive_half<C: BlockEncrypt<BlockSize = cipher::consts::U16>>(
+    kgk: &C,
+    i: u32,
+    nonce: [u8; 12],
+) -> [u8; 8] {
+    let mut block = Block::default();
+    block[..4].copy_from_slice(&i.to_le_bytes());
+    block[4..].copy_from_slice(&nonce);
+    kgk.encrypt_block(&mut block);
+    block[..8].try_into().unwrap()
+}
+
+/// RFC 8452 section 4 per-nonce key derivation from a 256-bit
+/// key-generating key: `mac_key = AES_K(0||N)[:8] || AES_K(1||N)[:8]`,
+/// `enc_key = AES_K(2||N)[:8] || ... || AES_K(5||N)[:8]`.
+fn gcm_siv_256_derive_keys(key: &[u8], nonce: [u8; 12]) -> (Vec<u8>, Vec<u8>) {
+    let kgk = Aes256::new(GenericArray::from_slice(key));
+    let mut mac_key = gcm_siv_derive_half(&kgk, 0, nonce).to_vec();
+    mac_key.extend_from_slice(&gcm_siv_derive_half(&kgk, 1, nonce));
+    let mut enc_key = gcm_siv_derive_half(&kgk, 2, nonce).to_vec();
+    enc_key.extend_from_slice(&gcm_siv_derive_half(&kgk, 3, nonce));
+    enc_key.extend_from_slice(&gcm_siv_derive_half(&kgk, 4, nonce));
+    enc_key.extend_from_slice(&gcm_siv_derive_half(&kgk, 5, nonce));
+    (mac_key, enc_key)
+}
+
+/// Same derivation as [`gcm_siv_256_derive_keys`], but from a 128-bit
+/// key-generating key: the auth and enc keys each come from only two
+/// counter-block halves instead of two and four (RFC 8452 section 4).
+fn gcm_siv_128_derive_keys(key: &[u8], nonce: [u8; 12]) -> (Vec<u8>, Vec<u8>) {
+    let kgk = Aes128::new(GenericArray::from_slice(key));
+    let mut mac_key = gcm_siv_derive_half(&kgk, 0, nonce).to_vec();
+    mac_key.extend_from_slice(&gcm_siv_derive_half(&kgk, 1, nonce));
+    let mut enc_key = gcm_siv_derive_half(&kgk, 2, nonce).to_vec();
+    enc_key.extend_from_slice(&gcm_siv_derive_half(&kgk, 3, nonce));
+    (mac_key, enc_key)
+}
+
+impl AeadWitness for GcmSiv256 {
+    const TAG_SIZE: usize = 16;
+    type Nonce = [u8; 12];
+
+    fn gen(key: &[u8], nonce: Self::Nonce, aad: &[u8], pt: &[u8]) -> Result<Witness> {
+        use aes_gcm_siv::{
+            aead::{Aead, Payload as SIVPayload},
+            Aes256GcmSiv,
+        };
+
+        let (mac_key, enc_key) = gcm_siv_256_derive_keys(key, nonce);
+        let mut h = Block::default();
+        h.copy_from_slice(&mac_key[..16]);
+
+        // POLYVAL(mac_key, AAD || PT || len(AAD)_64_LE || len(PT)_64_LE)
+        let mut blocks = pad_to_blocks(aad);
+        blocks.extend(pad_to_blocks(pt));
+        let mut len_block = Block::default();
+        len_block[..8].copy_from_slice(&((aad.len() as u64) * 8).to_le_bytes());
+        len_block[8..].copy_from_slice(&((pt.len() as u64) * 8).to_le_bytes());
+        blocks.push(len_block);
+        let s_s = polyval(&h, &blocks);
+
+        // XOR the nonce into the low 12 bytes, clear the top bit of byte 15.
+        let mut tag_input = s_s;
+        for (t, n) in tag_input.iter_mut().zip(nonce.iter()) {
+            *t ^= n;
+        }
+        tag_input[15] &= 0x7f;
+
+        let enc_cipher = Aes256::new(GenericArray::from_slice(&enc_key));
+        let mut tag = tag_input;
+        enc_cipher.encrypt_block(&mut tag);
+
+        // CTR32-LE keystream, seeded from the tag with its top bit set.
+        let mut ctr_seed = tag;
+        ctr_seed[15] |= 0x80;
+
+        let cipher = Aes256GcmSiv::new_from_slice(key).unwrap();
+        let nonce_arr = GenericArray::from_slice(&nonce);
+        let aes_payload = SIVPayload { msg: pt, aad };
+        let ciphertext = cipher.encrypt(nonce_arr, aes_payload).expect("error generating ct");
+
+        Ok(Witness::new_gcm_siv(key, &nonce, &ciphertext, pt, &mac_key, &enc_key, &s_s, &tag, &ctr_seed))
+    }
+}
+
+/// Shared decrypt-and-verify logic for AES-256-GCM-SIV: seal `pt` to
+/// obtain a ciphertext and genuine tag, optionally corrupt the tag,
+/// recover the plaintext via CTR32-LE and check the tag in constant time.
+fn gcm_siv_256_decrypt(key: &[u8], nonce: [u8; 12], aad: &[u8], pt: &[u8], forge: bool) -> Result<Witness> {
+    use aes_gcm_siv::{
+        aead::{Aead, Payload as SIVPayload},
+        Aes256GcmSiv,
+    };
+
+    let (mac_key, enc_key) = gcm_siv_256_derive_keys(key, nonce);
+    let mut h = Block::default();
+    h.copy_from_slice(&mac_key[..16]);
+
+    let cipher = Aes256GcmSiv::new_from_slice(key).unwrap();
+    let nonce_arr = GenericArray::from_slice(&nonce);
+    let aes_payload = SIVPayload { msg: pt, aad };
+    let sealed = cipher.encrypt(nonce_arr, aes_payload).expect("error generating ct");
+    let (ciphertext, genuine_tag_bytes) = sealed.split_at(sealed.len() - 16);
+    let mut received_tag = Block::default();
+    received_tag.copy_from_slice(genuine_tag_bytes);
+    if forge {
+        received_tag[0] ^= 0x01;
+    }
+
+    // CTR32-LE keystream, seeded from the received tag, recovers the
+    // plaintext from the ciphertext.
+    let enc_cipher = Aes256::new(GenericArray::from_slice(&enc_key));
+    let mut ctr_block = received_tag;
+    ctr_block[15] |= 0x80;
+    let ctr_seed = ctr_block;
+    let mut plaintext = ciphertext.to_vec();
+    for chunk in plaintext.chunks_mut(16) {
+        let mut keystream = ctr_block;
+        enc_cipher.encrypt_block(&mut keystream);
+        for (p, k) in chunk.iter_mut().zip(keystream.iter()) {
+            *p ^= k;
+        }
+        let counter = u32::from_le_bytes(ctr_block[..4].try_into().unwrap());
+        ctr_block[..4].copy_from_slice(&counter.wrapping_add(1).to_le_bytes());
+    }
+
+    // Recompute POLYVAL over AAD + the recovered plaintext to re-derive
+    // the expected tag.
+    let mut blocks = pad_to_blocks(aad);
+    blocks.extend(pad_to_blocks(&plaintext));
+    let mut len_block = Block::default();
+    len_block[..8].copy_from_slice(&((aad.len() as u64) * 8).to_le_bytes());
+    len_block[8..].copy_from_slice(&((plaintext.len() as u64) * 8).to_le_bytes());
+    blocks.push(len_block);
+    let s_s = polyval(&h, &blocks);
+
+    let mut tag_input = s_s;
+    for (t, n) in tag_input.iter_mut().zip(nonce.iter()) {
+        *t ^= n;
+    }
+    tag_input[15] &= 0x7f;
+    let mut expected_tag = tag_input;
+    enc_cipher.encrypt_block(&mut expected_tag);
+
+    let authentic: bool = expected_tag.ct_eq(&received_tag).into();
+
+    Ok(Witness::new_gcm_siv_decrypt(
+        key,
+        &nonce,
+        ciphertext,
+        &plaintext,
+        &mac_key,
+        &enc_key,
+        &s_s,
+        &ctr_seed,
+        &expected_tag,
+        &received_tag,
+        authentic,
+    ))
+}
+
+impl AeadWitness for GcmSiv256Decrypt {
+    const TAG_SIZE: usize = 16;
+    type Nonce = [u8; 12];
+
+    fn gen(key: &[u8], nonce: Self::Nonce, aad: &[u8], pt: &[u8]) -> Result<Witness> {
+        gcm_siv_256_decrypt(key, nonce, aad, pt, false)
+    }
+}
+
+impl AeadWitness for GcmSiv256DecryptForged {
+    const TAG_SIZE: usize = 16;
+    type Nonce = [u8; 12];
+
+    fn gen(key: &[u8], nonce: Self::Nonce, aad: &[u8], pt: &[u8]) -> Result<Witness> {
+        gcm_siv_256_decrypt(key, nonce, aad, pt, true)
+    }
+}
+
+/// AES-128-GCM-SIV (RFC 8452), the same construction as [`GcmSiv256`] but
+/// with a 16-byte key-generating key, so both key sizes can be validated
+/// against their respective circom artifacts from the same binary.
+pub(crate) struct GcmSiv128;
+
+impl AeadWitness for GcmSiv128 {
+    const TAG_SIZE: usize = 16;
+    type Nonce = [u8; 12];
+
+    fn gen(key: &[u8], nonce: Self::Nonce, aad: &[u8], pt: &[u8]) -> Result<Witness> {
+        use aes_gcm_siv::{
+            aead::{Aead, Payload as SIVPayload},
+            Aes128GcmSiv,
+        };
+
+        let (mac_key, enc_key) = gcm_siv_128_derive_keys(key, nonce);
+        let mut h = Block::default();
+        h.copy_from_slice(&mac_key[..16]);
+
+        // POLYVAL(mac_key, AAD || PT || len(AAD)_64_LE || len(PT)_64_LE)
+        let mut blocks = pad_to_blocks(aad);
+        blocks.extend(pad_to_blocks(pt));
+        let mut len_block = Block::default();
+        len_block[..8].copy_from_slice(&((aad.len() as u64) * 8).to_le_bytes());
+        len_block[8..].copy_from_slice(&((pt.len() as u64) * 8).to_le_bytes());
+        blocks.push(len_block);
+        let s_s = polyval(&h, &blocks);
+
+        // XOR the nonce into the low 12 bytes, clear the top bit of byte 15.
+        let mut tag_input = s_s;
+        for (t, n) in tag_input.iter_mut().zip(nonce.iter()) {
+            *t ^= n;
+        }
+        tag_input[15] &= 0x7f;
+
+        let enc_cipher = Aes128::new(GenericArray::from_slice(&enc_key));
+        let mut tag = tag_input;
+        enc_cipher.encrypt_block(&mut tag);
+
+        // CTR32-LE keystream, seeded from the tag with its top bit set.
+        let mut ctr_seed = tag;
+        ctr_seed[15] |= 0x80;
+
+        let cipher = Aes128GcmSiv::new_from_slice(key).unwrap();
+        let nonce_arr = GenericArray::from_slice(&nonce);
+        let aes_payload = SIVPayload { msg: pt, aad };
+        let ciphertext = cipher.encrypt(nonce_arr, aes_payload).expect("error generating ct");
+
+        Ok(Witness::new_gcm_siv(key, &nonce, &ciphertext, pt, &mac_key, &enc_key, &s_s, &tag, &ctr_seed))
+    }
+}
+
+/// Bridge an [`AeadWitness`] impl's strongly-typed `gen` to the
+/// byte-slice signature the [`registry`] stores, converting the nonce and
+/// surfacing a length mismatch as an error instead of a panic.
+fn gen_bytes<T: AeadWitness>(key: &[u8], nonce: &[u8], aad: &[u8], pt: &[u8]) -> Result<Witness> {
+    let nonce = T::Nonce::try_from(nonce)
+        .map_err(|_| anyhow::anyhow!("wrong nonce length for this AEAD construction"))?;
+    T::gen(key, nonce, aad, pt)
+}
+
+type BoxedWitnessFn = Box<dyn Fn(&[u8], &[u8], &[u8], &[u8]) -> Result<Witness>>;
+
+/// Registry mapping a mode name to its [`AeadWitness`] generator, so new
+/// AEAD constructions can be plugged in (see [`AeadWitness`]) without
+/// touching `aead_witness` or its callers.
+pub(crate) fn registry() -> HashMap<&'static str, BoxedWitnessFn> {
+    let mut modes: HashMap<&'static str, BoxedWitnessFn> = HashMap::new();
+    modes.insert("gcm128", Box::new(gen_bytes::<Gcm128>));
+    modes.insert("gcm128_decrypt", Box::new(gen_bytes::<Gcm128Decrypt>));
+    modes.insert("gcm128_decrypt_forged", Box::new(gen_bytes::<Gcm128DecryptForged>));
+    modes.insert("gcm_siv", Box::new(gen_bytes::<GcmSiv256>));
+    modes.insert("gcm_siv_decrypt", Box::new(gen_bytes::<GcmSiv256Decrypt>));
+    modes.insert("gcm_siv_decrypt_forged", Box::new(gen_bytes::<GcmSiv256DecryptForged>));
+    modes.insert("gcm_siv_128", Box::new(gen_bytes::<GcmSiv128>));
+    modes
+}
+
+/// Generate a witness for the named AEAD construction (see [`registry`]),
+/// e.g. `"gcm128"` or `"gcm_siv_decrypt"`.
+pub fn aead_witness(mode: &str, key: &[u8], nonce: &[u8], aad: &[u8], pt: &[u8]) -> Result<Witness> {
+    let modes = registry();
+    let gen = modes
+        .get(mode)
+        .ok_or_else(|| anyhow::anyhow!("unknown AEAD witness mode: {mode}"))?;
+    gen(key, nonce, aad, pt)
+}
+
+/// Simpler, non-AEAD demo modes: a single vanilla block encryption, raw
+/// CTR keystreams, and the off-the-shelf AES-256-GCM from the `aes_gcm`
+/// crate. These don't have the tag/nonce shape `AeadWitness` models, so
+/// they're kept as a plain `CipherMode` dispatch.
 #[derive(Default)]
 pub(crate) enum CipherMode {
+    #[default]
     Vanilla, // no IV Here
     Ctr256,
-    GcmSiv,
     GCM256,
     Ctr128,
-    /// AES-GCM-128 bit
-    #[default]
-    GCM128,
+}
+
+impl CipherMode {
+    /// Short, filesystem-safe name used for the generated witness file.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            CipherMode::Vanilla => "vanilla",
+            CipherMode::Ctr256 => "ctr256",
+            CipherMode::GCM256 => "gcm256",
+            CipherMode::Ctr128 => "ctr128",
+        }
+    }
 }
 
 /// borrowed from rust-tls
@@ -89,6 +751,9 @@ fn encrypt_tls(message: &[u8], key: &[u8], iv: &[u8], seq: u64) -> Result<Vec<u8
     Ok(cipher.encrypt(nonce, aes_payload).expect("error generating ct"))
 }
 
+/// Generate a witness for `cipher_mode`. These are the non-AEAD demo
+/// modes; see [`aead_witness`] for GCM-128/GCM-SIV and their
+/// decrypt-and-verify counterparts.
 pub fn aes_witnesses(cipher_mode: CipherMode) -> Result<Witness> {
     // Base ASCII versions using TLS encryption.
     let ct = encrypt_tls(MESSAGE.as_bytes(), KEY_ASCII.as_bytes(), IV_ASCII.as_bytes(), 1).unwrap();
@@ -115,23 +780,6 @@ pub fn aes_witnesses(cipher_mode: CipherMode) -> Result<Witness> {
             cipher_256.apply_keystream(&mut block_256);
             block_256.to_vec()
         },
-        CipherMode::GcmSiv => {
-            // AES GCM SIV, WOO MATCHES CIRCOM!! ✅
-            use aes_gcm_siv::{
-                aead::{Aead, Payload as SIVPayload},
-                Aes256GcmSiv,
-            };
-            let cipher = Aes256GcmSiv::new_from_slice(&key_256).unwrap();
-            let nonce = GenericArray::from_slice(&IV_BYTES_SHORT_256);
-            let aes_payload = SIVPayload { msg: &ZERO_MESSAGE_BYTES_256, aad: &SIV_AAD };
-            let ciphertext_siv = cipher.encrypt(nonce, aes_payload).expect("error generating ct");
-            println!(
-                "AES GCM 256 SIV: ct={:?}, bytes={:?}",
-                hex::encode(ciphertext_siv.clone()),
-                ciphertext_siv
-            );
-            ciphertext_siv.to_vec()
-        },
         CipherMode::GCM256 => {
             // Standard AES 256 GCM
             let cipher = Aes256Gcm::new_from_slice(&key_256).unwrap();
@@ -148,9 +796,6 @@ pub fn aes_witnesses(cipher_mode: CipherMode) -> Result<Witness> {
             cipher.apply_keystream(&mut block);
             block.to_vec()
         },
-        CipherMode::GCM128 => {
-            unimplemented!()
-        },
     };
 
     // more manual AESGCM using rust crypto should be equiv to output of encrypt tls